@@ -0,0 +1,105 @@
+//! Represent colors in CMYK (Cyan, Magenta, Yellow, Key/black) and convert between CMYK and
+//! RGB, for print-oriented use cases.
+
+/// Color represented in CMYK (Cyan, Magenta, Yellow, Key/black), with each channel in 0...1.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct CMYK {
+    /// Cyan in 0...1 (percent)
+    pub c: f64,
+    /// Magenta in 0...1 (percent)
+    pub m: f64,
+    /// Yellow in 0...1 (percent)
+    pub y: f64,
+    /// Key (black) in 0...1 (percent)
+    pub k: f64,
+}
+
+impl CMYK {
+    /// Convert RGB pixel value to CMYK
+    ///
+    /// ```rust
+    /// use hsl::CMYK;
+    /// let blue = CMYK::from_rgb(&[0, 0, 255]);
+    /// ```
+    pub fn from_rgb(rgb: &[u8]) -> CMYK {
+        let (r, g, b) = (rgb[0] as f64 / 255_f64,
+                         rgb[1] as f64 / 255_f64,
+                         rgb[2] as f64 / 255_f64);
+
+        let k = 1_f64 - r.max(g).max(b);
+
+        if k == 1_f64 {
+            return CMYK { c: 0_f64, m: 0_f64, y: 0_f64, k: k };
+        }
+
+        CMYK {
+            c: (1_f64 - r - k) / (1_f64 - k),
+            m: (1_f64 - g - k) / (1_f64 - k),
+            y: (1_f64 - b - k) / (1_f64 - k),
+            k: k,
+        }
+    }
+
+    /// Convert CMYK color to RGB
+    ///
+    /// ```rust
+    /// use hsl::CMYK;
+    ///
+    /// let cyan = CMYK { c: 1_f64, m: 0_f64, y: 0_f64, k: 0_f64 };
+    /// assert_eq!(cyan.to_rgb(), (0, 255, 255));
+    /// ```
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        (percent_to_byte((1_f64 - self.c) * (1_f64 - self.k)),
+         percent_to_byte((1_f64 - self.m) * (1_f64 - self.k)),
+         percent_to_byte((1_f64 - self.y) * (1_f64 - self.k)))
+    }
+}
+
+fn percent_to_byte(percent: f64) -> u8 {
+    (percent * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+    use super::*;
+
+    #[derive(Clone, Debug, Hash, PartialEq)]
+    struct RGB {
+        r: u8, g: u8, b: u8,
+    }
+
+    impl Arbitrary for RGB {
+        fn arbitrary<G: Gen>(g: &mut G) -> RGB {
+            RGB {
+                r: g.gen(),
+                g: g.gen(),
+                b: g.gen(),
+            }
+        }
+    }
+
+    fn sloppy_rgb_compare(a: RGB, b: RGB) -> bool {
+        const EPSILON: i32 = 1;
+        let res = (a.r as i32 - b.r as i32).abs() <= EPSILON &&
+                  (a.g as i32 - b.g as i32).abs() <= EPSILON &&
+                  (a.b as i32 - b.b as i32).abs() <= EPSILON;
+
+        if !res {
+            println!("in: {:?}, out: {:?}", a, b);
+        }
+
+        res
+    }
+
+    fn idemponent(input: RGB) -> bool {
+        let RGB { r, g, b } = input;
+        let (r_out, g_out, b_out) = CMYK::from_rgb(&[r, g, b]).to_rgb();
+        sloppy_rgb_compare(input, RGB { r: r_out, g: g_out, b: b_out })
+    }
+
+    #[test]
+    fn quickcheck_rgb_to_cmyk_and_back() {
+        quickcheck(idemponent as fn(RGB) -> bool);
+    }
+}
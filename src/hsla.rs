@@ -0,0 +1,196 @@
+//! Represent colors in HSL with an alpha channel, and parse/format CSS-style color strings.
+
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use HSL;
+
+/// Error returned when a string could not be parsed as a color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseColorError;
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid color string")
+    }
+}
+
+impl error::Error for ParseColorError {
+    fn description(&self) -> &str {
+        "invalid color string"
+    }
+}
+
+/// Color represented in HSL, with an additional alpha (opacity) channel.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct HSLA {
+    /// Hue in 0-360 degree
+    pub h: f64,
+    /// Saturation in 0...1 (percent)
+    pub s: f64,
+    /// Luminosity in 0...1 (percent)
+    pub l: f64,
+    /// Opacity in 0...1 (percent), where 0 is fully transparent and 1 is fully opaque
+    pub a: f64,
+}
+
+impl Default for HSLA {
+    fn default() -> HSLA {
+        HSLA { h: 0_f64, s: 0_f64, l: 0_f64, a: 1_f64 }
+    }
+}
+
+impl HSLA {
+    /// Combine an `HSL` color with an alpha value into an `HSLA`.
+    ///
+    /// ```rust
+    /// use hsl::{HSL, HSLA};
+    ///
+    /// let yellow = HSL { h: 60_f64, s: 1_f64, l: 0.5_f64 };
+    /// let translucent_yellow = HSLA::from_hsl(yellow, 0.5);
+    /// ```
+    pub fn from_hsl(hsl: HSL, a: f64) -> HSLA {
+        HSLA { h: hsl.h, s: hsl.s, l: hsl.l, a: a }
+    }
+
+    /// Discard the alpha channel, returning a plain `HSL`.
+    pub fn to_hsl(&self) -> HSL {
+        HSL { h: self.h, s: self.s, l: self.l }
+    }
+
+    /// Format this color as a CSS color string, e.g. `hsl(60, 100%, 50%)` or, when the
+    /// alpha channel is not fully opaque, `hsla(60, 100%, 50%, 0.5)`.
+    ///
+    /// ```rust
+    /// use hsl::HSLA;
+    ///
+    /// let yellow = HSLA { h: 60_f64, s: 1_f64, l: 0.5_f64, a: 1_f64 };
+    /// assert_eq!(yellow.to_css_string(), "hsl(60, 100%, 50%)");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        if self.a >= 1_f64 {
+            format!("hsl({}, {}%, {}%)", round(self.h), round(self.s * 100_f64), round(self.l * 100_f64))
+        } else {
+            format!("hsla({}, {}%, {}%, {})",
+                    round(self.h), round(self.s * 100_f64), round(self.l * 100_f64), round(self.a))
+        }
+    }
+}
+
+impl fmt::Display for HSLA {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_css_string())
+    }
+}
+
+impl FromStr for HSLA {
+    type Err = ParseColorError;
+
+    /// Parse a color string in one of the following notations: `#RGB`, `#RRGGBB`,
+    /// `#RRGGBBAA`, `rgb(r, g, b)`, `rgba(r, g, b, a)`, `hsl(h, s%, l%)` or
+    /// `hsla(h, s%, l%, a)`.
+    ///
+    /// ```rust
+    /// use hsl::HSLA;
+    ///
+    /// let yellow: HSLA = "#ff0".parse().unwrap();
+    /// assert_eq!(yellow.to_hsl().h, 60_f64);
+    /// ```
+    fn from_str(s: &str) -> Result<HSLA, ParseColorError> {
+        let s = s.trim();
+
+        if let Some(hex) = strip_prefix(s, "#") {
+            return parse_hex(hex);
+        }
+
+        if let Some(inner) = strip_wrapped(s, "rgba").or_else(|| strip_wrapped(s, "rgb")) {
+            return parse_rgb(inner);
+        }
+
+        if let Some(inner) = strip_wrapped(s, "hsla").or_else(|| strip_wrapped(s, "hsl")) {
+            return parse_hsl(inner);
+        }
+
+        Err(ParseColorError)
+    }
+}
+
+fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if s.starts_with(prefix) { Some(&s[prefix.len()..]) } else { None }
+}
+
+fn strip_wrapped<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    if s.starts_with(name) && s.ends_with(')') {
+        Some(s[name.len()..s.len() - 1].trim_start_matches('(').trim())
+    } else {
+        None
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<HSLA, ParseColorError> {
+    let expand = |c: char| -> Result<u8, ParseColorError> {
+        let byte = format!("{}{}", c, c);
+        u8::from_str_radix(&byte, 16).map_err(|_| ParseColorError)
+    };
+    let pair = |s: &str| -> Result<u8, ParseColorError> {
+        u8::from_str_radix(s, 16).map_err(|_| ParseColorError)
+    };
+
+    if !hex.is_ascii() {
+        return Err(ParseColorError);
+    }
+
+    let chars: Vec<char> = hex.chars().collect();
+    let (r, g, b, a) = match chars.len() {
+        3 => (expand(chars[0])?, expand(chars[1])?, expand(chars[2])?, 255),
+        6 => (pair(&hex[0..2])?, pair(&hex[2..4])?, pair(&hex[4..6])?, 255),
+        8 => (pair(&hex[0..2])?, pair(&hex[2..4])?, pair(&hex[4..6])?, pair(&hex[6..8])?),
+        _ => return Err(ParseColorError),
+    };
+
+    Ok(HSLA::from_hsl(HSL::from_rgb(&[r, g, b]), a as f64 / 255_f64))
+}
+
+fn parse_rgb(inner: &str) -> Result<HSLA, ParseColorError> {
+    let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(ParseColorError);
+    }
+
+    let byte = |p: &str| p.parse::<u8>().map_err(|_| ParseColorError);
+    let (r, g, b) = (byte(parts[0])?, byte(parts[1])?, byte(parts[2])?);
+    let a = if parts.len() == 4 {
+        parts[3].parse::<f64>().map_err(|_| ParseColorError)?
+    } else {
+        1_f64
+    };
+
+    Ok(HSLA::from_hsl(HSL::from_rgb(&[r, g, b]), a))
+}
+
+fn parse_hsl(inner: &str) -> Result<HSLA, ParseColorError> {
+    let parts: Vec<&str> = inner.split(',').map(|p| p.trim()).collect();
+    if parts.len() != 3 && parts.len() != 4 {
+        return Err(ParseColorError);
+    }
+
+    let percent = |p: &str| -> Result<f64, ParseColorError> {
+        p.trim_end_matches('%').parse::<f64>().map(|v| v / 100_f64).map_err(|_| ParseColorError)
+    };
+
+    let h = parts[0].parse::<f64>().map_err(|_| ParseColorError)?;
+    let s = percent(parts[1])?;
+    let l = percent(parts[2])?;
+    let a = if parts.len() == 4 {
+        parts[3].parse::<f64>().map_err(|_| ParseColorError)?
+    } else {
+        1_f64
+    };
+
+    Ok(HSLA { h: h, s: s, l: l, a: a })
+}
+
+fn round(n: f64) -> f64 {
+    (n * 100_f64).round() / 100_f64
+}
@@ -0,0 +1,276 @@
+//! Represent colors in HSLuv, a perceptually-uniform cylindrical color space built on top of
+//! CIELUV, and convert between HSLuv and RGB.
+//!
+//! Unlike plain [`HSL`](::HSL), equal steps in HSLuv's lightness and saturation correspond
+//! much more closely to equal steps in perceived brightness and colorfulness, which makes it
+//! a better fit for generating palettes and gradients.
+
+use std::f64::consts::PI;
+
+/// sRGB D65 linear RGB -> XYZ matrix.
+const M: [[f64; 3]; 3] = [
+    [0.41239079926595948, 0.35758433938387796, 0.18048078840183429],
+    [0.21263900587151027, 0.71516867876775593, 0.072192315360733715],
+    [0.019330818715591851, 0.11919477979462598, 0.95053215224966058],
+];
+
+/// sRGB D65 XYZ -> linear RGB matrix (inverse of [`M`]).
+const M_INV: [[f64; 3]; 3] = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+const REF_U: f64 = 0.19783000664283681;
+const REF_V: f64 = 0.46831999493879100;
+const KAPPA: f64 = 903.296;
+const EPSILON: f64 = 0.008856;
+
+/// Color represented in HSLuv, a perceptually-uniform alternative to [`HSL`](::HSL).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct HSLuv {
+    /// Hue in 0-360 degree
+    pub h: f64,
+    /// Saturation in 0...1 (percent), relative to the maximum chroma available at this
+    /// lightness and hue
+    pub s: f64,
+    /// Perceptual lightness in 0...1 (percent)
+    pub l: f64,
+}
+
+impl HSLuv {
+    /// Convert RGB pixel value to HSLuv
+    ///
+    /// ```rust
+    /// use hsl::HSLuv;
+    /// let blue = HSLuv::from_rgb(&[0, 0, 255]);
+    /// ```
+    pub fn from_rgb(rgb: &[u8]) -> HSLuv {
+        let linear = [to_linear(rgb[0] as f64 / 255_f64),
+                      to_linear(rgb[1] as f64 / 255_f64),
+                      to_linear(rgb[2] as f64 / 255_f64)];
+        let xyz = apply_matrix(&M, &linear);
+        let (l, u, v) = xyz_to_luv(xyz);
+        let (l, c, h) = luv_to_lch(l, u, v);
+        let (h, s, l) = lch_to_hsluv(l, c, h);
+
+        HSLuv { h: h, s: s / 100_f64, l: l / 100_f64 }
+    }
+
+    /// Convert HSLuv color to RGB
+    ///
+    /// ```rust
+    /// use hsl::HSLuv;
+    ///
+    /// let blue = HSLuv { h: 265.874_f64, s: 1_f64, l: 0.323_f64 };
+    /// ```
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let (l, c, h) = hsluv_to_lch(self.h, self.s * 100_f64, self.l * 100_f64);
+        let (l, u, v) = lch_to_luv(l, c, h);
+        let xyz = luv_to_xyz(l, u, v);
+        let linear = apply_matrix(&M_INV, &xyz);
+
+        (percent_to_byte(from_linear(linear[0])),
+         percent_to_byte(from_linear(linear[1])),
+         percent_to_byte(from_linear(linear[2])))
+    }
+}
+
+fn percent_to_byte(percent: f64) -> u8 {
+    (clamp(percent, 0_f64, 1_f64) * 255.0).round() as u8
+}
+
+fn clamp(n: f64, min: f64, max: f64) -> f64 {
+    if n < min { min } else if n > max { max } else { n }
+}
+
+fn to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn apply_matrix(matrix: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    [matrix[0][0] * v[0] + matrix[0][1] * v[1] + matrix[0][2] * v[2],
+     matrix[1][0] * v[0] + matrix[1][1] * v[1] + matrix[1][2] * v[2],
+     matrix[2][0] * v[0] + matrix[2][1] * v[1] + matrix[2][2] * v[2]]
+}
+
+fn y_to_l(y: f64) -> f64 {
+    if y <= EPSILON {
+        y * KAPPA
+    } else {
+        116_f64 * y.cbrt() - 16_f64
+    }
+}
+
+fn l_to_y(l: f64) -> f64 {
+    if l <= 8_f64 {
+        l / KAPPA
+    } else {
+        ((l + 16_f64) / 116_f64).powi(3)
+    }
+}
+
+fn xyz_to_luv(xyz: [f64; 3]) -> (f64, f64, f64) {
+    let (x, y, z) = (xyz[0], xyz[1], xyz[2]);
+    let denom = x + 15_f64 * y + 3_f64 * z;
+    let (var_u, var_v) = if denom == 0_f64 {
+        (0_f64, 0_f64)
+    } else {
+        (4_f64 * x / denom, 9_f64 * y / denom)
+    };
+
+    let l = y_to_l(y);
+    if l == 0_f64 {
+        return (0_f64, 0_f64, 0_f64);
+    }
+
+    let u = 13_f64 * l * (var_u - REF_U);
+    let v = 13_f64 * l * (var_v - REF_V);
+    (l, u, v)
+}
+
+fn luv_to_xyz(l: f64, u: f64, v: f64) -> [f64; 3] {
+    if l == 0_f64 {
+        return [0_f64, 0_f64, 0_f64];
+    }
+
+    let var_u = u / (13_f64 * l) + REF_U;
+    let var_v = v / (13_f64 * l) + REF_V;
+
+    let y = l_to_y(l);
+    let x = 0_f64 - (9_f64 * y * var_u) / ((var_u - 4_f64) * var_v - var_u * var_v);
+    let z = (9_f64 * y - 15_f64 * var_v * y - var_v * x) / (3_f64 * var_v);
+
+    [x, y, z]
+}
+
+fn luv_to_lch(l: f64, u: f64, v: f64) -> (f64, f64, f64) {
+    let c = (u * u + v * v).sqrt();
+    let h = if c < 0.00000001 {
+        0_f64
+    } else {
+        let hdeg = v.atan2(u).to_degrees();
+        if hdeg < 0_f64 { hdeg + 360_f64 } else { hdeg }
+    };
+    (l, c, h)
+}
+
+fn lch_to_luv(l: f64, c: f64, h: f64) -> (f64, f64, f64) {
+    let hrad = h.to_radians();
+    (l, hrad.cos() * c, hrad.sin() * c)
+}
+
+/// The six gamut-boundary lines (in the CIELUV `(u, v)` plane, for a given `L`) formed by each RGB
+/// channel clipping to 0 or 1, used to find the maximum chroma achievable at a lightness/hue.
+fn get_bounds(l: f64) -> Vec<(f64, f64)> {
+    let sub1 = (l + 16_f64).powi(3) / 1560896_f64;
+    let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+
+    let mut result = Vec::with_capacity(6);
+    for row in &M_INV {
+        let (m1, m2, m3) = (row[0], row[1], row[2]);
+        for t in 0..2 {
+            let t = t as f64;
+            let top1 = (284517_f64 * m1 - 94839_f64 * m3) * sub2;
+            let top2 = (838422_f64 * m3 + 769860_f64 * m2 + 731718_f64 * m1) * l * sub2 -
+                       769860_f64 * t * l;
+            let bottom = (632260_f64 * m3 - 126452_f64 * m2) * sub2 + 126452_f64 * t;
+            result.push((top1 / bottom, top2 / bottom));
+        }
+    }
+    result
+}
+
+fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+    let hrad = h / 360_f64 * 2_f64 * PI;
+    let mut min = f64::MAX;
+
+    for (m1, b1) in get_bounds(l) {
+        let length = b1 / (hrad.sin() - m1 * hrad.cos());
+        if length >= 0_f64 && length < min {
+            min = length;
+        }
+    }
+    min
+}
+
+fn hsluv_to_lch(h: f64, s: f64, l: f64) -> (f64, f64, f64) {
+    if l > 99.9999999 {
+        return (100_f64, 0_f64, h);
+    }
+    if l < 0.00000001 {
+        return (0_f64, 0_f64, h);
+    }
+
+    let max = max_chroma_for_lh(l, h);
+    (l, max / 100_f64 * s, h)
+}
+
+fn lch_to_hsluv(l: f64, c: f64, h: f64) -> (f64, f64, f64) {
+    if l > 99.9999999 {
+        return (h, 0_f64, 100_f64);
+    }
+    if l < 0.00000001 {
+        return (h, 0_f64, 0_f64);
+    }
+
+    let max = max_chroma_for_lh(l, h);
+    (h, c / max * 100_f64, l)
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+    use super::*;
+
+    #[derive(Clone, Debug, Hash, PartialEq)]
+    struct RGB {
+        r: u8, g: u8, b: u8,
+    }
+
+    impl Arbitrary for RGB {
+        fn arbitrary<G: Gen>(g: &mut G) -> RGB {
+            RGB {
+                r: g.gen(),
+                g: g.gen(),
+                b: g.gen(),
+            }
+        }
+    }
+
+    fn sloppy_rgb_compare(a: RGB, b: RGB) -> bool {
+        const EPSILON: i32 = 1;
+        let res = (a.r as i32 - b.r as i32).abs() <= EPSILON &&
+                  (a.g as i32 - b.g as i32).abs() <= EPSILON &&
+                  (a.b as i32 - b.b as i32).abs() <= EPSILON;
+
+        if !res {
+            println!("in: {:?}, out: {:?}", a, b);
+        }
+
+        res
+    }
+
+    fn idemponent(input: RGB) -> bool {
+        let RGB { r, g, b } = input;
+        let (r_out, g_out, b_out) = HSLuv::from_rgb(&[r, g, b]).to_rgb();
+        sloppy_rgb_compare(input, RGB { r: r_out, g: g_out, b: b_out })
+    }
+
+    #[test]
+    fn quickcheck_rgb_to_hsluv_and_back() {
+        quickcheck(idemponent as fn(RGB) -> bool);
+    }
+}
@@ -0,0 +1,135 @@
+//! Represent colors in HSV (also known as HSB) and convert between HSV and RGB.
+
+/// A small tolerance used when comparing the largest RGB channel against the
+/// individual channels, to avoid float-equality pitfalls.
+const EPSILON: f64 = 1e-10;
+
+/// Color represented in HSV (Hue, Saturation, Value), also known as HSB
+/// (Hue, Saturation, Brightness).
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct HSV {
+    /// Hue in 0-360 degree
+    pub h: f64,
+    /// Saturation in 0...1 (percent)
+    pub s: f64,
+    /// Value (brightness) in 0...1 (percent)
+    pub v: f64,
+}
+
+impl HSV {
+    /// Convert RGB pixel value to HSV
+    ///
+    /// Expects RGB pixel to be a slice of three `u8`s representing the red, green and blue
+    /// values.
+    ///
+    /// ```rust
+    /// use hsl::HSV;
+    /// let blue = HSV::from_rgb(&[0, 0, 255]);
+    /// ```
+    pub fn from_rgb(rgb: &[u8]) -> HSV {
+        let (r, g, b) = (rgb[0] as f64 / 255_f64,
+                         rgb[1] as f64 / 255_f64,
+                         rgb[2] as f64 / 255_f64);
+
+        let value = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = value - min;
+
+        let h = if chroma.abs() < EPSILON {
+            0_f64
+        } else if (value - r).abs() < EPSILON {
+            60_f64 * (((g - b) / chroma) % 6_f64)
+        } else if (value - g).abs() < EPSILON {
+            60_f64 * (2_f64 + (b - r) / chroma)
+        } else {
+            60_f64 * (4_f64 + (r - g) / chroma)
+        };
+
+        let h = if h < 0_f64 { h + 360_f64 } else { h };
+
+        let s = if value.abs() < EPSILON { 0_f64 } else { chroma / value };
+
+        HSV { h: h, s: s, v: value }
+    }
+
+    /// Convert HSV color to RGB
+    ///
+    /// ```rust
+    /// use hsl::HSV;
+    ///
+    /// let cyan = HSV { h: 180_f64, s: 1_f64, v: 1_f64 };
+    /// assert_eq!(cyan.to_rgb(), (0, 255, 255));
+    /// ```
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let chroma = self.s * self.v;
+        let h_prime = self.h / 60_f64;
+        let second = chroma * (1_f64 - (h_prime % 2_f64 - 1_f64).abs());
+
+        let (r1, g1, b1) = if h_prime < 1_f64 {
+            (chroma, second, 0_f64)
+        } else if h_prime < 2_f64 {
+            (second, chroma, 0_f64)
+        } else if h_prime < 3_f64 {
+            (0_f64, chroma, second)
+        } else if h_prime < 4_f64 {
+            (0_f64, second, chroma)
+        } else if h_prime < 5_f64 {
+            (second, 0_f64, chroma)
+        } else {
+            (chroma, 0_f64, second)
+        };
+
+        let m = self.v - chroma;
+
+        (percent_to_byte(r1 + m), percent_to_byte(g1 + m), percent_to_byte(b1 + m))
+    }
+}
+
+fn percent_to_byte(percent: f64) -> u8 {
+    (percent * 255.0).round() as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+    use super::*;
+
+    #[derive(Clone, Debug, Hash, PartialEq)]
+    struct RGB {
+        r: u8, g: u8, b: u8,
+    }
+
+    impl Arbitrary for RGB {
+        fn arbitrary<G: Gen>(g: &mut G) -> RGB {
+            RGB {
+                r: g.gen(),
+                g: g.gen(),
+                b: g.gen(),
+            }
+        }
+    }
+
+    fn sloppy_rgb_compare(a: RGB, b: RGB) -> bool {
+        const EPSILON: i32 = 1;
+        let res = (a.r as i32 - b.r as i32).abs() <= EPSILON &&
+                  (a.g as i32 - b.g as i32).abs() <= EPSILON &&
+                  (a.b as i32 - b.b as i32).abs() <= EPSILON;
+
+        if !res {
+            println!("in: {:?}, out: {:?}", a, b);
+        }
+
+        res
+    }
+
+    fn idemponent(input: RGB) -> bool {
+        let RGB { r, g, b } = input;
+        let (r_out, g_out, b_out) = HSV::from_rgb(&[r, g, b]).to_rgb();
+        sloppy_rgb_compare(input, RGB { r: r_out, g: g_out, b: b_out })
+    }
+
+    #[test]
+    fn quickcheck_rgb_to_hsv_and_back() {
+        quickcheck(idemponent as fn(RGB) -> bool);
+    }
+}
@@ -0,0 +1,187 @@
+//! Represent colors in CIE LCh (the cylindrical form of CIELAB), and convert between LCh and
+//! RGB via gamma-correct CIEXYZ/CIELAB transforms.
+
+/// sRGB D65 linear RGB -> XYZ matrix.
+const M: [[f64; 3]; 3] = [
+    [0.41239079926595948, 0.35758433938387796, 0.18048078840183429],
+    [0.21263900587151027, 0.71516867876775593, 0.072192315360733715],
+    [0.019330818715591851, 0.11919477979462598, 0.95053215224966058],
+];
+
+/// sRGB D65 XYZ -> linear RGB matrix (inverse of [`M`]).
+const M_INV: [[f64; 3]; 3] = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+
+/// D65 reference white, used as the denominator when normalizing XYZ before computing Lab.
+const WHITE: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+/// Color represented in CIE LCh, the cylindrical form of CIELAB.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
+pub struct LCH {
+    /// Lightness in 0-100
+    pub l: f64,
+    /// Chroma, unbounded but typically 0-~150
+    pub c: f64,
+    /// Hue in 0-360 degree
+    pub h: f64,
+}
+
+impl LCH {
+    /// Convert RGB pixel value to LCh
+    ///
+    /// ```rust
+    /// use hsl::LCH;
+    /// let blue = LCH::from_rgb(&[0, 0, 255]);
+    /// ```
+    pub fn from_rgb(rgb: &[u8]) -> LCH {
+        let linear = [to_linear(rgb[0] as f64 / 255_f64),
+                      to_linear(rgb[1] as f64 / 255_f64),
+                      to_linear(rgb[2] as f64 / 255_f64)];
+        let xyz = apply_matrix(&M, &linear);
+        let (l, a, b) = xyz_to_lab(xyz);
+        let (l, c, h) = lab_to_lch(l, a, b);
+
+        LCH { l: l, c: c, h: h }
+    }
+
+    /// Convert LCh color to RGB
+    ///
+    /// ```rust
+    /// use hsl::LCH;
+    ///
+    /// let blue = LCH { l: 32.301_f64, c: 133.818_f64, h: 306.285_f64 };
+    /// ```
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let (l, a, b) = lch_to_lab(self.l, self.c, self.h);
+        let xyz = lab_to_xyz(l, a, b);
+        let linear = apply_matrix(&M_INV, &xyz);
+
+        (percent_to_byte(from_linear(linear[0])),
+         percent_to_byte(from_linear(linear[1])),
+         percent_to_byte(from_linear(linear[2])))
+    }
+}
+
+fn percent_to_byte(percent: f64) -> u8 {
+    (clamp(percent, 0_f64, 1_f64) * 255.0).round() as u8
+}
+
+fn clamp(n: f64, min: f64, max: f64) -> f64 {
+    if n < min { min } else if n > max { max } else { n }
+}
+
+fn to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn from_linear(c: f64) -> f64 {
+    if c <= 0.0031308 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn apply_matrix(matrix: &[[f64; 3]; 3], v: &[f64; 3]) -> [f64; 3] {
+    [matrix[0][0] * v[0] + matrix[0][1] * v[1] + matrix[0][2] * v[2],
+     matrix[1][0] * v[0] + matrix[1][1] * v[1] + matrix[1][2] * v[2],
+     matrix[2][0] * v[0] + matrix[2][1] * v[1] + matrix[2][2] * v[2]]
+}
+
+const LAB_EPSILON: f64 = 216_f64 / 24389_f64;
+const LAB_KAPPA: f64 = 24389_f64 / 27_f64;
+
+fn lab_f(t: f64) -> f64 {
+    if t > LAB_EPSILON {
+        t.cbrt()
+    } else {
+        (LAB_KAPPA * t + 16_f64) / 116_f64
+    }
+}
+
+fn lab_f_inv(t: f64) -> f64 {
+    if t.powi(3) > LAB_EPSILON {
+        t.powi(3)
+    } else {
+        (116_f64 * t - 16_f64) / LAB_KAPPA
+    }
+}
+
+fn xyz_to_lab(xyz: [f64; 3]) -> (f64, f64, f64) {
+    let fx = lab_f(xyz[0] / WHITE[0]);
+    let fy = lab_f(xyz[1] / WHITE[1]);
+    let fz = lab_f(xyz[2] / WHITE[2]);
+
+    (116_f64 * fy - 16_f64, 500_f64 * (fx - fy), 200_f64 * (fy - fz))
+}
+
+fn lab_to_xyz(l: f64, a: f64, b: f64) -> [f64; 3] {
+    let fy = (l + 16_f64) / 116_f64;
+    let fx = fy + a / 500_f64;
+    let fz = fy - b / 200_f64;
+
+    [lab_f_inv(fx) * WHITE[0], lab_f_inv(fy) * WHITE[1], lab_f_inv(fz) * WHITE[2]]
+}
+
+fn lab_to_lch(l: f64, a: f64, b: f64) -> (f64, f64, f64) {
+    let c = (a * a + b * b).sqrt();
+    let h = b.atan2(a).to_degrees();
+    (l, c, if h < 0_f64 { h + 360_f64 } else { h })
+}
+
+fn lch_to_lab(l: f64, c: f64, h: f64) -> (f64, f64, f64) {
+    let hrad = h.to_radians();
+    (l, c * hrad.cos(), c * hrad.sin())
+}
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::{Arbitrary, Gen, quickcheck};
+    use super::*;
+
+    #[derive(Clone, Debug, Hash, PartialEq)]
+    struct RGB {
+        r: u8, g: u8, b: u8,
+    }
+
+    impl Arbitrary for RGB {
+        fn arbitrary<G: Gen>(g: &mut G) -> RGB {
+            RGB {
+                r: g.gen(),
+                g: g.gen(),
+                b: g.gen(),
+            }
+        }
+    }
+
+    fn sloppy_rgb_compare(a: RGB, b: RGB) -> bool {
+        const EPSILON: i32 = 1;
+        let res = (a.r as i32 - b.r as i32).abs() <= EPSILON &&
+                  (a.g as i32 - b.g as i32).abs() <= EPSILON &&
+                  (a.b as i32 - b.b as i32).abs() <= EPSILON;
+
+        if !res {
+            println!("in: {:?}, out: {:?}", a, b);
+        }
+
+        res
+    }
+
+    fn idemponent(input: RGB) -> bool {
+        let RGB { r, g, b } = input;
+        let (r_out, g_out, b_out) = LCH::from_rgb(&[r, g, b]).to_rgb();
+        sloppy_rgb_compare(input, RGB { r: r_out, g: g_out, b: b_out })
+    }
+
+    #[test]
+    fn quickcheck_rgb_to_lch_and_back() {
+        quickcheck(idemponent as fn(RGB) -> bool);
+    }
+}
@@ -15,6 +15,21 @@
 
 #[cfg(test)] extern crate quickcheck;
 
+mod hsv;
+pub use hsv::HSV;
+
+mod hsla;
+pub use hsla::{HSLA, ParseColorError};
+
+mod hsluv;
+pub use hsluv::HSLuv;
+
+mod lch;
+pub use lch::LCH;
+
+mod cmyk;
+pub use cmyk::CMYK;
+
 /// Color represented in HSL
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Default)]
 pub struct HSL {
@@ -130,6 +145,215 @@ impl HSL {
          percent_to_byte(hue_to_rgb(p, q, h)),
          percent_to_byte(hue_to_rgb(p, q, h - 1.0 / 3.0)))
     }
+
+    /// Convert HSL color to HSV (also known as HSB)
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let yellow = HSL { h: 60_f64, s: 1_f64, l: 0.5_f64 };
+    /// let yellow_hsv = yellow.to_hsv();
+    /// ```
+    pub fn to_hsv(&self) -> HSV {
+        let (r, g, b) = self.to_rgb();
+        HSV::from_rgb(&[r, g, b])
+    }
+
+    /// Convert HSV color to HSL
+    ///
+    /// ```rust
+    /// use hsl::{HSL, HSV};
+    ///
+    /// let yellow_hsv = HSV { h: 60_f64, s: 1_f64, v: 1_f64 };
+    /// let yellow = HSL::from_hsv(&yellow_hsv);
+    /// ```
+    pub fn from_hsv(hsv: &HSV) -> HSL {
+        let (r, g, b) = hsv.to_rgb();
+        HSL::from_rgb(&[r, g, b])
+    }
+
+    /// Convert a packed `0xRRGGBBAA` value to HSL, discarding the alpha byte.
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let yellow = HSL::from_hex(0xffff00ff);
+    /// assert_eq!(yellow, HSL { h: 60_f64, s: 1_f64, l: 0.5_f64 });
+    /// ```
+    pub fn from_hex(hex: u32) -> HSL {
+        let r = ((hex >> 24) & 0xff) as u8;
+        let g = ((hex >> 16) & 0xff) as u8;
+        let b = ((hex >> 8) & 0xff) as u8;
+
+        HSL::from_rgb(&[r, g, b])
+    }
+
+    /// Convert this color to a packed `0xRRGGBBAA` value, with the alpha byte set to fully
+    /// opaque (`0xff`).
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let yellow = HSL { h: 60_f64, s: 1_f64, l: 0.5_f64 };
+    /// assert_eq!(yellow.to_hex(), 0xffff00ff);
+    /// ```
+    pub fn to_hex(&self) -> u32 {
+        let (r, g, b) = self.to_rgb();
+        ((r as u32) << 24) | ((g as u32) << 16) | ((b as u32) << 8) | 0xff
+    }
+
+    /// Lighten this color by `amount`, a fraction (0..1) of the remaining distance to full
+    /// lightness. Returns a new `HSL` with `l` clamped to 0..1.
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let grey = HSL { h: 0_f64, s: 0_f64, l: 0.5_f64 };
+    /// assert_eq!(grey.lighten(0.5).l, 0.75_f64);
+    /// ```
+    pub fn lighten(&self, amount: f64) -> HSL {
+        HSL { l: clamp(self.l + amount * (1_f64 - self.l), 0_f64, 1_f64), ..*self }
+    }
+
+    /// Darken this color by `amount`, a fraction (0..1) of the remaining distance to zero
+    /// lightness. Returns a new `HSL` with `l` clamped to 0..1.
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let grey = HSL { h: 0_f64, s: 0_f64, l: 0.5_f64 };
+    /// assert_eq!(grey.darken(0.5).l, 0.25_f64);
+    /// ```
+    pub fn darken(&self, amount: f64) -> HSL {
+        HSL { l: clamp(self.l - amount * self.l, 0_f64, 1_f64), ..*self }
+    }
+
+    /// Saturate this color by `amount`, a fraction (0..1) of the remaining distance to full
+    /// saturation. Returns a new `HSL` with `s` clamped to 0..1.
+    pub fn saturate(&self, amount: f64) -> HSL {
+        HSL { s: clamp(self.s + amount * (1_f64 - self.s), 0_f64, 1_f64), ..*self }
+    }
+
+    /// Desaturate this color by `amount`, a fraction (0..1) of the remaining distance to zero
+    /// saturation. Returns a new `HSL` with `s` clamped to 0..1.
+    pub fn desaturate(&self, amount: f64) -> HSL {
+        HSL { s: clamp(self.s - amount * self.s, 0_f64, 1_f64), ..*self }
+    }
+
+    /// Rotate the hue by `degrees`, wrapping around the 0..360 hue circle. Negative values
+    /// rotate backwards.
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let red = HSL { h: 350_f64, s: 1_f64, l: 0.5_f64 };
+    /// assert_eq!(red.rotate_hue(20_f64).h, 10_f64);
+    /// ```
+    pub fn rotate_hue(&self, degrees: f64) -> HSL {
+        let h = (self.h + degrees) % 360_f64;
+        HSL { h: if h < 0_f64 { h + 360_f64 } else { h }, ..*self }
+    }
+
+    /// Remove all saturation, turning this color into a shade of grey while keeping its
+    /// lightness.
+    pub fn grayscale(&self) -> HSL {
+        HSL { s: 0_f64, ..*self }
+    }
+
+    /// Linearly interpolate between this color and `other`, with `t` in 0..1.
+    ///
+    /// Hue is interpolated along the shortest path around the 360° circle, so
+    /// interpolating between `350°` and `10°` passes through `0°` rather than sweeping
+    /// backwards through the rest of the wheel.
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let red = HSL { h: 350_f64, s: 1_f64, l: 0.5_f64 };
+    /// let orange = HSL { h: 10_f64, s: 1_f64, l: 0.5_f64 };
+    /// assert_eq!(red.lerp(&orange, 0.5).h, 0_f64);
+    /// ```
+    pub fn lerp(&self, other: &HSL, t: f64) -> HSL {
+        let mut from_h = self.h;
+        let mut to_h = other.h;
+
+        if (to_h - from_h).abs() > 180_f64 {
+            if from_h < to_h {
+                from_h += 360_f64;
+            } else {
+                to_h += 360_f64;
+            }
+        }
+
+        let h = (from_h + (to_h - from_h) * t) % 360_f64;
+
+        HSL {
+            h: if h < 0_f64 { h + 360_f64 } else { h },
+            s: self.s + (other.s - self.s) * t,
+            l: self.l + (other.l - self.l) * t,
+        }
+    }
+
+    /// Generate `steps` evenly spaced colors along the [`lerp`](HSL::lerp) path from this
+    /// color to `other`, inclusive of both endpoints.
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let black = HSL { h: 0_f64, s: 0_f64, l: 0_f64 };
+    /// let white = HSL { h: 0_f64, s: 0_f64, l: 1_f64 };
+    /// let gradient = black.gradient(&white, 3);
+    /// assert_eq!(gradient.len(), 3);
+    /// assert_eq!(gradient[1].l, 0.5_f64);
+    /// ```
+    pub fn gradient(&self, other: &HSL, steps: usize) -> Vec<HSL> {
+        if steps == 0 {
+            return Vec::new();
+        }
+        if steps == 1 {
+            return vec![*self];
+        }
+
+        (0..steps)
+            .map(|i| self.lerp(other, i as f64 / (steps - 1) as f64))
+            .collect()
+    }
+
+    /// Convert HSL color to CMYK
+    ///
+    /// ```rust
+    /// use hsl::HSL;
+    ///
+    /// let yellow = HSL { h: 60_f64, s: 1_f64, l: 0.5_f64 };
+    /// let yellow_cmyk = yellow.to_cmyk();
+    /// ```
+    pub fn to_cmyk(&self) -> CMYK {
+        let (r, g, b) = self.to_rgb();
+        CMYK::from_rgb(&[r, g, b])
+    }
+
+    /// Convert CMYK color to HSL
+    ///
+    /// ```rust
+    /// use hsl::{HSL, CMYK};
+    ///
+    /// let yellow_cmyk = CMYK { c: 0_f64, m: 0_f64, y: 1_f64, k: 0_f64 };
+    /// let yellow = HSL::from_cmyk(&yellow_cmyk);
+    /// ```
+    pub fn from_cmyk(cmyk: &CMYK) -> HSL {
+        let (r, g, b) = cmyk.to_rgb();
+        HSL::from_rgb(&[r, g, b])
+    }
+}
+
+fn clamp(n: f64, min: f64, max: f64) -> f64 {
+    if n < min {
+        min
+    } else if n > max {
+        max
+    } else {
+        n
+    }
 }
 
 fn percent_to_byte(percent: f64) -> u8 {